@@ -0,0 +1,209 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// A currency code known to Coinbase, encoded compactly as a single nonzero `u8` for local
+/// caching/storage while still round-tripping the ISO-4217/ticker string (e.g. `"USD"`,
+/// `"BTC"`) on the wire.
+///
+/// `0` is reserved for "no code / unknown" and is rejected by `TryFrom<u8>` so that a corrupt or
+/// unrecognized stored value surfaces as an explicit error instead of silently aliasing a known
+/// currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CurrencyCode {
+    Usd,
+    Eur,
+    Gbp,
+    Jpy,
+    Cad,
+    Aud,
+    Chf,
+    Btc,
+    Eth,
+    Ltc,
+    Usdc,
+}
+
+impl CurrencyCode {
+    /// The ISO-4217/ticker string Coinbase uses for this currency on the wire.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CurrencyCode::Usd => "USD",
+            CurrencyCode::Eur => "EUR",
+            CurrencyCode::Gbp => "GBP",
+            CurrencyCode::Jpy => "JPY",
+            CurrencyCode::Cad => "CAD",
+            CurrencyCode::Aud => "AUD",
+            CurrencyCode::Chf => "CHF",
+            CurrencyCode::Btc => "BTC",
+            CurrencyCode::Eth => "ETH",
+            CurrencyCode::Ltc => "LTC",
+            CurrencyCode::Usdc => "USDC",
+        }
+    }
+}
+
+/// Error returned when a string or byte doesn't map to a known [`CurrencyCode`].
+#[derive(Debug)]
+pub struct UnknownCurrencyCode(String);
+
+impl fmt::Display for UnknownCurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown currency code: {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownCurrencyCode {}
+
+impl TryFrom<&str> for CurrencyCode {
+    type Error = UnknownCurrencyCode;
+
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        match code {
+            "USD" => Ok(CurrencyCode::Usd),
+            "EUR" => Ok(CurrencyCode::Eur),
+            "GBP" => Ok(CurrencyCode::Gbp),
+            "JPY" => Ok(CurrencyCode::Jpy),
+            "CAD" => Ok(CurrencyCode::Cad),
+            "AUD" => Ok(CurrencyCode::Aud),
+            "CHF" => Ok(CurrencyCode::Chf),
+            "BTC" => Ok(CurrencyCode::Btc),
+            "ETH" => Ok(CurrencyCode::Eth),
+            "LTC" => Ok(CurrencyCode::Ltc),
+            "USDC" => Ok(CurrencyCode::Usdc),
+            other => Err(UnknownCurrencyCode(other.to_string())),
+        }
+    }
+}
+
+impl TryFrom<u8> for CurrencyCode {
+    type Error = UnknownCurrencyCode;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            1 => Ok(CurrencyCode::Usd),
+            2 => Ok(CurrencyCode::Eur),
+            3 => Ok(CurrencyCode::Gbp),
+            4 => Ok(CurrencyCode::Jpy),
+            5 => Ok(CurrencyCode::Cad),
+            6 => Ok(CurrencyCode::Aud),
+            7 => Ok(CurrencyCode::Chf),
+            8 => Ok(CurrencyCode::Btc),
+            9 => Ok(CurrencyCode::Eth),
+            10 => Ok(CurrencyCode::Ltc),
+            11 => Ok(CurrencyCode::Usdc),
+            0 => Err(UnknownCurrencyCode("0 (no code)".to_string())),
+            other => Err(UnknownCurrencyCode(other.to_string())),
+        }
+    }
+}
+
+impl From<CurrencyCode> for u8 {
+    fn from(code: CurrencyCode) -> Self {
+        match code {
+            CurrencyCode::Usd => 1,
+            CurrencyCode::Eur => 2,
+            CurrencyCode::Gbp => 3,
+            CurrencyCode::Jpy => 4,
+            CurrencyCode::Cad => 5,
+            CurrencyCode::Aud => 6,
+            CurrencyCode::Chf => 7,
+            CurrencyCode::Btc => 8,
+            CurrencyCode::Eth => 9,
+            CurrencyCode::Ltc => 10,
+            CurrencyCode::Usdc => 11,
+        }
+    }
+}
+
+impl Serialize for CurrencyCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CurrencyCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        CurrencyCode::try_from(code.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Compact 1-byte (de)serialization for [`CurrencyCode`], for `#[serde(with =
+/// "currency_code::compact")]` on local caching/storage formats (e.g. `bincode`). This is not
+/// the wire format used when talking to Coinbase, which is always the plain string form above.
+pub mod compact {
+    use super::CurrencyCode;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::convert::TryFrom;
+
+    pub fn serialize<S>(code: &CurrencyCode, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        u8::from(*code).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<CurrencyCode, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let byte = u8::deserialize(deserializer)?;
+        CurrencyCode::try_from(byte).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Stored {
+        #[serde(with = "compact")]
+        code: CurrencyCode,
+    }
+
+    #[test]
+    fn test_str_round_trip() {
+        for code in [CurrencyCode::Usd, CurrencyCode::Btc, CurrencyCode::Usdc] {
+            assert_eq!(CurrencyCode::try_from(code.as_str()).unwrap(), code);
+        }
+        assert!(CurrencyCode::try_from("XYZ").is_err());
+    }
+
+    #[test]
+    fn test_u8_round_trip() {
+        for code in [CurrencyCode::Usd, CurrencyCode::Btc, CurrencyCode::Usdc] {
+            assert_eq!(CurrencyCode::try_from(u8::from(code)).unwrap(), code);
+        }
+        assert!(CurrencyCode::try_from(0u8).is_err());
+        assert!(CurrencyCode::try_from(255u8).is_err());
+    }
+
+    #[test]
+    fn test_wire_serde_uses_string() {
+        let json = serde_json::to_string(&CurrencyCode::Btc).unwrap();
+        assert_eq!(json, "\"BTC\"");
+        let code: CurrencyCode = serde_json::from_str("\"ETH\"").unwrap();
+        assert_eq!(code, CurrencyCode::Eth);
+    }
+
+    #[test]
+    fn test_compact_serde_round_trips_through_the_numeric_code() {
+        let stored = Stored {
+            code: CurrencyCode::Btc,
+        };
+        let json = serde_json::to_string(&stored).unwrap();
+        assert_eq!(json, format!("{{\"code\":{}}}", u8::from(CurrencyCode::Btc)));
+
+        let round_tripped: Stored = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped.code, CurrencyCode::Btc);
+    }
+}
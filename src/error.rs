@@ -0,0 +1,181 @@
+use hyper::StatusCode;
+use serde::Deserialize;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum CBError {
+    Http(hyper::Error),
+    Serde {
+        error: serde_json::Error,
+        data: String,
+    },
+    /// An error from a streaming feed, e.g. the WebSocket ticker feed: either one the feed itself
+    /// reported, or a failure to connect to it, subscribe to it, or stay connected to it.
+    Protocol {
+        message: String,
+        reason: Option<String>,
+    },
+    /// A non-2xx response from a REST call, classified by HTTP status and `errors[].id`.
+    Api(ApiError),
+    /// No price path (direct or bridged) could be found between two currencies, e.g. in
+    /// [`Public::approx_spot_price`](crate::Public::approx_spot_price). This is a local
+    /// condition, not something Coinbase itself reported.
+    NoPricePath { from: String, to: String },
+}
+
+impl fmt::Display for CBError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CBError::Http(err) => write!(f, "http error: {}", err),
+            CBError::Serde { error, data } => {
+                write!(f, "failed to deserialize `{}`: {}", data, error)
+            }
+            CBError::Protocol { message, reason } => match reason {
+                Some(reason) => write!(f, "feed error: {} ({})", message, reason),
+                None => write!(f, "feed error: {}", message),
+            },
+            CBError::Api(err) => write!(f, "{}", err),
+            CBError::NoPricePath { from, to } => {
+                write!(f, "no direct or bridged price path from {} to {}", from, to)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CBError {}
+
+/// One entry of Coinbase's `{"errors": [...]}` error envelope.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiErrorDetail {
+    pub id: String,
+    pub message: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct ApiErrorEnvelope {
+    #[serde(default)]
+    pub errors: Vec<ApiErrorDetail>,
+}
+
+/// How a non-2xx REST response was classified, derived from the HTTP status and `errors[].id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    InvalidCurrency,
+    NotFound,
+    RateLimited,
+    Auth,
+    Server,
+    Other,
+}
+
+impl ApiErrorKind {
+    pub(crate) fn classify(status: StatusCode, errors: &[ApiErrorDetail]) -> Self {
+        match errors.first().map(|error| error.id.as_str()) {
+            Some("invalid_currency") => return ApiErrorKind::InvalidCurrency,
+            Some("not_found") => return ApiErrorKind::NotFound,
+            Some("rate_limit_exceeded") => return ApiErrorKind::RateLimited,
+            Some("authentication_error") | Some("invalid_token") | Some("unauthorized") => {
+                return ApiErrorKind::Auth
+            }
+            _ => {}
+        }
+
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => ApiErrorKind::RateLimited,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ApiErrorKind::Auth,
+            StatusCode::NOT_FOUND => ApiErrorKind::NotFound,
+            status if status.is_server_error() => ApiErrorKind::Server,
+            _ => ApiErrorKind::Other,
+        }
+    }
+}
+
+/// A classified, non-2xx REST response.
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub kind: ApiErrorKind,
+    pub errors: Vec<ApiErrorDetail>,
+    /// The `Retry-After` header, if the response carried one as a whole number of seconds
+    /// (typically set alongside a `429`). A `Retry-After` given as an HTTP date is not parsed and
+    /// leaves this `None`.
+    pub retry_after: Option<Duration>,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<&str> = self.errors.iter().map(|e| e.message.as_str()).collect();
+        write!(
+            f,
+            "api error ({:?}, status {}): {}",
+            self.kind,
+            self.status,
+            messages.join("; ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn detail(id: &str) -> ApiErrorDetail {
+        ApiErrorDetail {
+            id: id.to_string(),
+            message: "message".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_classify_by_error_id_takes_priority_over_status() {
+        assert_eq!(
+            ApiErrorKind::classify(StatusCode::BAD_REQUEST, &[detail("invalid_currency")]),
+            ApiErrorKind::InvalidCurrency
+        );
+        assert_eq!(
+            ApiErrorKind::classify(StatusCode::BAD_REQUEST, &[detail("rate_limit_exceeded")]),
+            ApiErrorKind::RateLimited
+        );
+        assert_eq!(
+            ApiErrorKind::classify(StatusCode::BAD_REQUEST, &[detail("invalid_token")]),
+            ApiErrorKind::Auth
+        );
+    }
+
+    #[test]
+    fn test_classify_falls_back_to_status_without_a_recognized_error_id() {
+        assert_eq!(
+            ApiErrorKind::classify(StatusCode::TOO_MANY_REQUESTS, &[]),
+            ApiErrorKind::RateLimited
+        );
+        assert_eq!(
+            ApiErrorKind::classify(StatusCode::UNAUTHORIZED, &[]),
+            ApiErrorKind::Auth
+        );
+        assert_eq!(
+            ApiErrorKind::classify(StatusCode::FORBIDDEN, &[]),
+            ApiErrorKind::Auth
+        );
+        assert_eq!(
+            ApiErrorKind::classify(StatusCode::NOT_FOUND, &[]),
+            ApiErrorKind::NotFound
+        );
+        assert_eq!(
+            ApiErrorKind::classify(StatusCode::INTERNAL_SERVER_ERROR, &[]),
+            ApiErrorKind::Server
+        );
+        assert_eq!(
+            ApiErrorKind::classify(StatusCode::BAD_REQUEST, &[]),
+            ApiErrorKind::Other
+        );
+    }
+
+    #[test]
+    fn test_classify_ignores_unrecognized_error_ids() {
+        assert_eq!(
+            ApiErrorKind::classify(StatusCode::SERVICE_UNAVAILABLE, &[detail("something_else")]),
+            ApiErrorKind::Server
+        );
+    }
+}
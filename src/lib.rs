@@ -0,0 +1,20 @@
+//! A client for the public Coinbase API.
+
+pub mod currency_code;
+mod error;
+mod price_stream;
+mod public;
+
+pub use currency_code::{CurrencyCode, UnknownCurrencyCode};
+pub use error::CBError;
+pub use price_stream::{PriceStream, TickerUpdate};
+pub use public::{Public, RetryPolicy};
+
+/// Base URL of the production Coinbase API.
+pub const MAIN_URL: &str = "https://api.coinbase.com/v2";
+
+/// Base URL of the Coinbase sandbox environment.
+pub const SANDBOX_URL: &str = "https://api-public.sandbox.coinbase.com/v2";
+
+/// Timestamps returned by the Coinbase API.
+pub type DateTime = chrono::DateTime<chrono::Utc>;
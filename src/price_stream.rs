@@ -0,0 +1,272 @@
+use crate::error::CBError;
+use bigdecimal::BigDecimal;
+use futures::stream::{self, Stream, StreamExt};
+use futures::SinkExt;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+/// Default WebSocket feed used by [`PriceStream`].
+///
+/// https://docs.cloud.coinbase.com/exchange/docs/websocket-overview
+pub const FEED_URL: &str = "wss://ws-feed.exchange.coinbase.com";
+
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// A single `ticker` update for one product.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TickerUpdate {
+    pub product_id: String,
+    pub price: BigDecimal,
+    pub best_bid: BigDecimal,
+    pub best_ask: BigDecimal,
+}
+
+#[derive(Serialize)]
+struct SubscribeMessage<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    product_ids: &'a [String],
+    channels: &'static [&'static str],
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum FeedMessage {
+    Subscriptions {
+        #[allow(dead_code)]
+        channels: serde_json::Value,
+    },
+    Heartbeat {
+        #[allow(dead_code)]
+        sequence: u64,
+    },
+    Error {
+        message: String,
+        reason: Option<String>,
+    },
+    Ticker(TickerUpdate),
+}
+
+enum State {
+    Disconnected { attempt: u32 },
+    Connected { socket: Box<WsStream>, attempt: u32 },
+}
+
+/// A stream of live `ticker` updates from Coinbase's WebSocket feed.
+///
+/// Unlike [`Public`](crate::Public), which polls `buy_price`/`sell_price`/`spot_price` on
+/// demand, `PriceStream` opens a single WebSocket connection and yields an update every time
+/// the best bid, best ask, or last trade price changes for one of the subscribed products. The
+/// connection is transparently re-established with exponential backoff if it drops.
+pub struct PriceStream {
+    inner: Pin<Box<dyn Stream<Item = Result<TickerUpdate, CBError>> + Send>>,
+}
+
+impl PriceStream {
+    /// Subscribe to the `ticker` channel for `product_ids` (e.g. `"BTC-USD"`) on the default
+    /// feed ([`FEED_URL`]). The connection is opened lazily, the first time the stream is
+    /// polled.
+    pub fn new(product_ids: Vec<String>) -> Self {
+        Self::with_feed(FEED_URL, product_ids)
+    }
+
+    /// Like [`PriceStream::new`], but against a custom feed URL (e.g. the sandbox feed).
+    pub fn with_feed(feed_url: &str, product_ids: Vec<String>) -> Self {
+        let feed_url = feed_url.to_string();
+        let inner = Box::pin(stream::unfold(
+            State::Disconnected { attempt: 0 },
+            move |state| {
+                let feed_url = feed_url.clone();
+                let product_ids = product_ids.clone();
+                async move { Self::advance(feed_url, product_ids, state).await }
+            },
+        ));
+
+        Self { inner }
+    }
+
+    async fn advance(
+        feed_url: String,
+        product_ids: Vec<String>,
+        state: State,
+    ) -> Option<(Result<TickerUpdate, CBError>, State)> {
+        match state {
+            State::Disconnected { attempt } => {
+                match Self::connect(&feed_url, &product_ids).await {
+                    Ok(socket) => {
+                        Box::pin(Self::advance(
+                            feed_url,
+                            product_ids,
+                            State::Connected {
+                                socket: Box::new(socket),
+                                attempt,
+                            },
+                        ))
+                        .await
+                    }
+                    Err(err) => {
+                        tokio::time::sleep(backoff(attempt)).await;
+                        Some((Err(err), State::Disconnected { attempt: attempt + 1 }))
+                    }
+                }
+            }
+            // `attempt` is carried through (and only reset once a ticker update actually
+            // arrives) so that a connection which drops right after the handshake still backs
+            // off, instead of spinning on `connect()` with no delay.
+            State::Connected { mut socket, attempt } => loop {
+                match socket.next().await {
+                    Some(Ok(Message::Text(text))) => match serde_json::from_str(&text) {
+                        Ok(FeedMessage::Ticker(update)) => {
+                            return Some((
+                                Ok(update),
+                                State::Connected { socket, attempt: 0 },
+                            ))
+                        }
+                        Ok(FeedMessage::Error { message, reason }) => {
+                            return Some((
+                                Err(CBError::Protocol { message, reason }),
+                                State::Connected { socket, attempt },
+                            ))
+                        }
+                        Ok(FeedMessage::Subscriptions { .. } | FeedMessage::Heartbeat { .. }) => {
+                            continue
+                        }
+                        Err(error) => {
+                            return Some((
+                                Err(CBError::Serde { error, data: text }),
+                                State::Connected { socket, attempt },
+                            ))
+                        }
+                    },
+                    Some(Ok(_)) => continue,
+                    Some(Err(_)) | None => {
+                        tokio::time::sleep(backoff(attempt)).await;
+                        return Some((
+                            Err(CBError::Protocol {
+                                message: "feed connection closed".into(),
+                                reason: None,
+                            }),
+                            State::Disconnected { attempt: attempt + 1 },
+                        ))
+                    }
+                }
+            },
+        }
+    }
+
+    async fn connect(feed_url: &str, product_ids: &[String]) -> Result<WsStream, CBError> {
+        let (mut socket, _) = connect_async(feed_url)
+            .await
+            .map_err(|err| CBError::Protocol {
+                message: "failed to connect to feed".into(),
+                reason: Some(err.to_string()),
+            })?;
+
+        let subscribe = SubscribeMessage {
+            kind: "subscribe",
+            product_ids,
+            channels: &["ticker"],
+        };
+        let subscribe = serde_json::to_string(&subscribe).map_err(|error| CBError::Serde {
+            error,
+            data: String::new(),
+        })?;
+        socket
+            .send(Message::Text(subscribe))
+            .await
+            .map_err(|err| CBError::Protocol {
+                message: "failed to send subscribe message".into(),
+                reason: Some(err.to_string()),
+            })?;
+
+        Ok(socket)
+    }
+}
+
+impl Stream for PriceStream {
+    type Item = Result<TickerUpdate, CBError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+fn backoff(attempt: u32) -> Duration {
+    Duration::from_secs(1 << attempt.min(5)).min(MAX_BACKOFF)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_ticker_deserialize() {
+        let input = r#"
+    {
+        "type": "ticker",
+        "sequence": 12345,
+        "product_id": "BTC-USD",
+        "price": "50000.00",
+        "best_bid": "49999.00",
+        "best_ask": "50001.00"
+    }"#;
+        let message: FeedMessage = serde_json::from_str(input).unwrap();
+        match message {
+            FeedMessage::Ticker(update) => {
+                assert_eq!(update.product_id, "BTC-USD");
+                assert_eq!(update.price, BigDecimal::from_str("50000.00").unwrap());
+                assert_eq!(update.best_bid, BigDecimal::from_str("49999.00").unwrap());
+                assert_eq!(update.best_ask, BigDecimal::from_str("50001.00").unwrap());
+            }
+            other => panic!("expected a ticker update, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_subscriptions_deserialize() {
+        let input = r#"
+    {
+        "type": "subscriptions",
+        "channels": [{"name": "ticker", "product_ids": ["BTC-USD"]}]
+    }"#;
+        let message: FeedMessage = serde_json::from_str(input).unwrap();
+        assert!(matches!(message, FeedMessage::Subscriptions { .. }));
+    }
+
+    #[test]
+    fn test_heartbeat_deserialize() {
+        let input = r#"
+    {
+        "type": "heartbeat",
+        "sequence": 12345
+    }"#;
+        let message: FeedMessage = serde_json::from_str(input).unwrap();
+        assert!(matches!(message, FeedMessage::Heartbeat { .. }));
+    }
+
+    #[test]
+    fn test_error_deserialize() {
+        let input = r#"
+    {
+        "type": "error",
+        "message": "invalid product id",
+        "reason": "BTC-XYZ not found"
+    }"#;
+        let message: FeedMessage = serde_json::from_str(input).unwrap();
+        match message {
+            FeedMessage::Error { message, reason } => {
+                assert_eq!(message, "invalid product id");
+                assert_eq!(reason.as_deref(), Some("BTC-XYZ not found"));
+            }
+            other => panic!("expected an error message, got {:?}", other),
+        }
+    }
+}
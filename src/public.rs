@@ -1,16 +1,50 @@
 use super::error::CBError;
+use crate::currency_code::{CurrencyCode, UnknownCurrencyCode};
+use crate::error::{ApiError, ApiErrorEnvelope, ApiErrorKind};
 use crate::DateTime;
-use bigdecimal::BigDecimal;
+use bigdecimal::{BigDecimal, Zero};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use hyper::body::Bytes;
 use hyper::client::{Client, HttpConnector};
 use hyper::{Body, Request, Uri};
 use hyper_tls::HttpsConnector;
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::future::Future;
+use std::time::Duration;
 
 pub struct Public {
     pub(crate) uri: String,
     client: Client<HttpsConnector<HttpConnector>>,
+    retry: Option<RetryPolicy>,
 }
 
+/// Opt-in retry behavior for rate-limited (`429`) and server-error (`5xx`) responses. Disabled
+/// by default; enable with [`Public::with_retry`].
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one. A rate-limited/server-error response
+    /// is retried up to `max_attempts - 1` times before being surfaced to the caller.
+    pub max_attempts: u32,
+    /// Backoff used when the response has no `Retry-After` header in seconds; doubled after each
+    /// attempt.
+    pub base_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Ceiling applied to the exponential backoff computed from [`RetryPolicy::base_backoff`], so
+/// that a large `max_attempts` can't produce an unreasonably long sleep. (The exponent itself is
+/// capped at 31 so that `2u32.pow(..)` can't overflow.)
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(60);
+
 impl Public {
     pub(crate) const USER_AGENT: &'static str = concat!("coinbase-rs/", env!("CARGO_PKG_VERSION"));
 
@@ -19,30 +53,34 @@ impl Public {
         let client = Client::builder().build::<_, Body>(https);
         let uri = uri.to_string();
 
-        Self { uri, client }
+        Self {
+            uri,
+            client,
+            retry: None,
+        }
     }
 
-    pub(crate) async fn call_future<U>(&self, request: Request<Body>) -> Result<U, CBError>
+    /// Enable retrying rate-limited and server-error responses according to `policy`, backing
+    /// off by the response's `Retry-After` header when it is given in seconds, or
+    /// `policy.base_backoff` (doubled per attempt) otherwise.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    pub(crate) async fn call_future<U>(&self, uri: &str) -> Result<U, CBError>
     where
         for<'de> U: serde::Deserialize<'de>,
     {
-        let response = self.client.request(request).await.map_err(CBError::Http)?;
-        let bytes = hyper::body::to_bytes(response.into_body())
-            .await
-            .map_err(CBError::Http)?;
+        let bytes = self.fetch(uri).await?;
         let res: serde_json::Value = serde_json::from_slice(&bytes).map_err(|e| {
-            serde_json::from_slice(&bytes)
-                .map(CBError::Coinbase)
-                .unwrap_or_else(|_| {
-                    let data = String::from_utf8(bytes.to_vec()).unwrap();
-                    CBError::Serde { error: e, data }
-                })
-        })?;
-        let data = serde_json::from_slice(res["data"].to_string().as_bytes()).map_err(|e| {
             let data = String::from_utf8(bytes.to_vec()).unwrap();
             CBError::Serde { error: e, data }
         })?;
-        Ok(data)
+        serde_json::from_value(res["data"].clone()).map_err(|error| {
+            let data = String::from_utf8(bytes.to_vec()).unwrap();
+            CBError::Serde { error, data }
+        })
     }
 
     async fn get_pub<U>(&self, uri: &str) -> Result<U, CBError>
@@ -50,7 +88,62 @@ impl Public {
         U: Send + 'static,
         for<'de> U: serde::Deserialize<'de>,
     {
-        self.call_future(self.request(uri)).await
+        self.call_future(uri).await
+    }
+
+    /// Issue a GET request to `uri`, retrying per `self.retry` (if enabled) on rate-limit/5xx
+    /// responses, and return the raw response body once a 2xx is received or retries are
+    /// exhausted.
+    async fn fetch(&self, uri: &str) -> Result<Bytes, CBError> {
+        let max_attempts = self.retry.as_ref().map_or(1, |policy| policy.max_attempts.max(1));
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+
+            let response = self
+                .client
+                .request(self.request(uri))
+                .await
+                .map_err(CBError::Http)?;
+            let status = response.status();
+            let retry_after = response
+                .headers()
+                .get(hyper::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(Duration::from_secs);
+            let bytes = hyper::body::to_bytes(response.into_body())
+                .await
+                .map_err(CBError::Http)?;
+
+            if status.is_success() {
+                return Ok(bytes);
+            }
+
+            let errors = serde_json::from_slice::<ApiErrorEnvelope>(&bytes)
+                .map(|envelope| envelope.errors)
+                .unwrap_or_default();
+            let kind = ApiErrorKind::classify(status, &errors);
+            let retryable = matches!(kind, ApiErrorKind::RateLimited | ApiErrorKind::Server);
+            let error = CBError::Api(ApiError {
+                status,
+                kind,
+                errors,
+                retry_after,
+            });
+
+            if !retryable || attempt >= max_attempts {
+                return Err(error);
+            }
+
+            let policy = self.retry.as_ref().expect("retry is Some when max_attempts > 1");
+            let exponent = attempt.saturating_sub(1).min(31);
+            let backoff = retry_after.unwrap_or_else(|| {
+                (policy.base_backoff * 2u32.pow(exponent)).min(MAX_RETRY_BACKOFF)
+            });
+            tokio::time::sleep(backoff).await;
+        }
     }
 
     fn request(&self, uri: &str) -> Request<Body> {
@@ -131,12 +224,68 @@ impl Public {
     pub async fn spot_price(
         &self,
         currency_pair: &str,
-        _date: Option<chrono::NaiveDate>,
+        date: Option<chrono::NaiveDate>,
     ) -> Result<CurrencyPrice, CBError> {
-        self.get_pub(&format!("/currency_pair/{}/spot", currency_pair))
+        let mut uri = format!("/currency_pair/{}/spot", currency_pair);
+        if let Some(date) = date {
+            uri.push_str(&format!("?date={}", date.format("%Y-%m-%d")));
+        }
+        self.get_pub(&uri).await
+    }
+
+    /// Number of dated `spot_price` requests [`Public::spot_price_history`] keeps in flight at
+    /// once.
+    const SPOT_PRICE_HISTORY_CONCURRENCY: usize = 8;
+
+    ///
+    /// **Get spot price history**
+    ///
+    /// Fetch one spot price per day over `[from, to]` (inclusive), issuing the dated requests
+    /// concurrently (bounded by [`Self::SPOT_PRICE_HISTORY_CONCURRENCY`]) and returning them in
+    /// date order. Useful for cost-basis/backfill tooling that needs a full price history rather
+    /// than just the current spot.
+    ///
+    pub async fn spot_price_history(
+        &self,
+        currency_pair: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<(chrono::NaiveDate, CurrencyPrice)>, CBError> {
+        stream::iter(date_range_inclusive(from, to))
+            .map(|date| async move {
+                self.spot_price(currency_pair, Some(date))
+                    .await
+                    .map(|price| (date, price))
+            })
+            .buffered(Self::SPOT_PRICE_HISTORY_CONCURRENCY)
+            .try_collect()
             .await
     }
 
+    /// Bridge currencies tried, in order, by [`Public::approx_spot_price`] when Coinbase has no
+    /// product for the requested pair directly.
+    const BRIDGE_CURRENCIES: &'static [&'static str] = &["BTC", "USD"];
+
+    ///
+    /// **Get an approximate spot price for a pair Coinbase doesn't quote directly**
+    ///
+    /// Tries `spot_price` for `from-to` first. If Coinbase has no such product, the rate is
+    /// derived by chaining through a bridge currency (BTC, then USD): `from-bridge` and
+    /// `bridge-to` are fetched concurrently and multiplied together, inverting either leg if
+    /// only the reverse pair is quoted. The returned [`ApproxSpotPrice`] records which bridge,
+    /// if any, was used so callers can audit synthetic quotes.
+    ///
+    pub async fn approx_spot_price(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<ApproxSpotPrice, CBError> {
+        approx_spot_price_via(from, to, |pair| async move {
+            self.spot_price(&pair, None).await.map(|price| price.amount)
+        })
+        .await
+    }
+
     ///
     /// **Get current time**
     ///
@@ -148,6 +297,173 @@ impl Public {
         self.get_pub("/current_time").await
         //.map(|c: Adapter<Result = Result<T, CBError>>| c.iso)
     }
+
+    ///
+    /// **Paginate a cursor-paged v2 endpoint**
+    ///
+    /// Issues the first request to `uri`, then keeps following `pagination.next_uri` until
+    /// Coinbase reports none, deserializing each page's `data` array element-by-element and
+    /// flattening across pages into a single stream.
+    ///
+    pub fn get_paged<U>(&self, uri: &str) -> impl Stream<Item = Result<U, CBError>> + '_
+    where
+        U: Send + 'static,
+        for<'de> U: serde::Deserialize<'de>,
+    {
+        paginate(uri.to_string(), move |uri: String| async move {
+            self.get_page::<U>(&uri).await
+        })
+    }
+
+    ///
+    /// **Collect a paginated endpoint into a `Vec`**
+    ///
+    /// Convenience wrapper around [`Public::get_paged`] that drains the stream into a single
+    /// `Vec`, stopping at the first error.
+    ///
+    pub async fn get_paged_collect<U>(&self, uri: &str) -> Result<Vec<U>, CBError>
+    where
+        U: Send + 'static,
+        for<'de> U: serde::Deserialize<'de>,
+    {
+        self.get_paged(uri).try_collect().await
+    }
+
+    /// Fetch one page of a cursor-paged endpoint, returning its items and the next page's URI
+    /// (if any).
+    async fn get_page<U>(&self, uri: &str) -> Result<(Vec<U>, Option<String>), CBError>
+    where
+        for<'de> U: serde::Deserialize<'de>,
+    {
+        let bytes = self.fetch(uri).await?;
+        let envelope: Response = serde_json::from_slice(&bytes).map_err(|error| {
+            let data = String::from_utf8(bytes.to_vec()).unwrap();
+            CBError::Serde { error, data }
+        })?;
+
+        let items: Vec<U> = serde_json::from_value(envelope.data).map_err(|error| CBError::Serde {
+            error,
+            data: String::new(),
+        })?;
+        let next_uri = (!envelope.pagination.next_uri.is_empty()).then_some(envelope.pagination.next_uri);
+
+        Ok((items, next_uri))
+    }
+}
+
+/// Cursor-following logic backing [`Public::get_paged`], generic over how a page is fetched so it
+/// can be unit-tested without any network access. `get_page` fetches one page's items and the
+/// next page's URI (if any); pagination stops once it returns `None` for the next URI, or as soon
+/// as it returns an error.
+fn paginate<F, Fut, U>(first_uri: String, get_page: F) -> impl Stream<Item = Result<U, CBError>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<(Vec<U>, Option<String>), CBError>>,
+{
+    stream::unfold(Some(first_uri), move |next_uri| {
+        let get_page = &get_page;
+        async move {
+            let uri = next_uri?;
+            match get_page(uri).await {
+                Ok((items, next_uri)) => Some((Ok(items), next_uri)),
+                Err(err) => Some((Err(err), None)),
+            }
+        }
+    })
+    .map(|page: Result<Vec<U>, CBError>| match page {
+        Ok(items) => stream::iter(items.into_iter().map(Ok).collect::<Vec<_>>()),
+        Err(err) => stream::iter(vec![Err(err)]),
+    })
+    .flatten()
+}
+
+/// Every date from `from` to `to`, inclusive, in order.
+fn date_range_inclusive(
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+) -> Vec<chrono::NaiveDate> {
+    let mut dates = Vec::new();
+    let mut date = from;
+    while date <= to {
+        dates.push(date);
+        date += chrono::Duration::days(1);
+    }
+    dates
+}
+
+/// Selection logic backing [`Public::approx_spot_price`], generic over how a `from-to` pair is
+/// quoted so it can be unit-tested without any network access. `quote` should return the spot
+/// price for a currency pair formatted `"{from}-{to}"`, or an error if Coinbase has no such
+/// product.
+async fn approx_spot_price_via<F, Fut>(
+    from: &str,
+    to: &str,
+    quote: F,
+) -> Result<ApproxSpotPrice, CBError>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<BigDecimal, CBError>>,
+{
+    if let Ok(amount) = quote(format!("{}-{}", from, to)).await {
+        return Ok(ApproxSpotPrice {
+            amount,
+            bridge: None,
+        });
+    }
+
+    for bridge in Public::BRIDGE_CURRENCIES {
+        if *bridge == from || *bridge == to {
+            continue;
+        }
+
+        let legs = tokio::try_join!(
+            leg_price_via(from, bridge, &quote),
+            leg_price_via(bridge, to, &quote)
+        );
+        if let Ok((from_bridge, bridge_to)) = legs {
+            return Ok(ApproxSpotPrice {
+                amount: combine_bridge_legs(from_bridge, bridge_to),
+                bridge: Some((*bridge).to_string()),
+            });
+        }
+    }
+
+    Err(CBError::NoPricePath {
+        from: from.to_string(),
+        to: to.to_string(),
+    })
+}
+
+/// Resolve a single leg of a bridged price: the direct pair if `quote` has it, otherwise the
+/// inverse of the reverse pair.
+async fn leg_price_via<F, Fut>(from: &str, to: &str, quote: &F) -> Result<BigDecimal, CBError>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<BigDecimal, CBError>>,
+{
+    if let Ok(amount) = quote(format!("{}-{}", from, to)).await {
+        return Ok(amount);
+    }
+
+    let inverse = quote(format!("{}-{}", to, from)).await?;
+    invert_price(inverse, from, to)
+}
+
+/// Invert a quoted price, e.g. turn a `to-from` rate into a `from-to` rate. Returns
+/// [`CBError::NoPricePath`] instead of dividing by zero if `amount` is zero.
+fn invert_price(amount: BigDecimal, from: &str, to: &str) -> Result<BigDecimal, CBError> {
+    if amount.is_zero() {
+        return Err(CBError::NoPricePath {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+    }
+    Ok(BigDecimal::from(1) / amount)
+}
+
+/// Combine two bridged legs (`from-bridge` and `bridge-to`) into a single `from-to` rate.
+fn combine_bridge_legs(from_bridge: BigDecimal, bridge_to: BigDecimal) -> BigDecimal {
+    from_bridge * bridge_to
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -183,6 +499,13 @@ pub struct Currency {
     pub min_size: BigDecimal,
 }
 
+impl Currency {
+    /// Parse this currency's ISO-4217/ticker `id` into a typed [`CurrencyCode`], if known.
+    pub fn code(&self) -> Result<CurrencyCode, UnknownCurrencyCode> {
+        CurrencyCode::try_from(self.id.as_str())
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct ExchangeRates {
     pub currency: String,
@@ -200,12 +523,248 @@ struct CurrentTime {
     iso: DateTime,
 }
 
+/// The result of [`Public::approx_spot_price`].
+#[derive(Debug)]
+pub struct ApproxSpotPrice {
+    pub amount: BigDecimal,
+    /// `None` if Coinbase quotes the pair directly; `Some(currency)` if `amount` was derived by
+    /// chaining through that bridge currency.
+    pub bridge: Option<String>,
+}
+
 #[cfg(test)]
 mod test {
     use bigdecimal::FromPrimitive;
+    use std::str::FromStr;
 
     use super::*;
 
+    fn quote_from(
+        rates: HashMap<&'static str, &'static str>,
+    ) -> impl Fn(String) -> std::future::Ready<Result<BigDecimal, CBError>> {
+        move |pair: String| {
+            std::future::ready(
+                rates
+                    .get(pair.as_str())
+                    .map(|amount| BigDecimal::from_str(amount).unwrap())
+                    .ok_or_else(|| CBError::NoPricePath {
+                        from: pair.clone(),
+                        to: pair,
+                    }),
+            )
+        }
+    }
+
+    fn page_source(
+        pages: HashMap<&'static str, (Vec<u32>, Option<&'static str>)>,
+    ) -> impl Fn(String) -> std::future::Ready<Result<(Vec<u32>, Option<String>), CBError>> {
+        move |uri: String| {
+            let page = pages
+                .get(uri.as_str())
+                .map(|(items, next)| (items.clone(), next.map(str::to_string)))
+                .ok_or_else(|| CBError::NoPricePath {
+                    from: uri.clone(),
+                    to: uri,
+                });
+            std::future::ready(page)
+        }
+    }
+
+    #[test]
+    fn test_paginate_flattens_multiple_pages_and_stops_when_next_uri_is_absent() {
+        let pages = page_source(HashMap::from([
+            ("/page1", (vec![1, 2], Some("/page2"))),
+            ("/page2", (vec![3], None)),
+        ]));
+
+        let items: Vec<u32> =
+            futures::executor::block_on(paginate("/page1".to_string(), pages).collect::<Vec<_>>())
+                .into_iter()
+                .map(Result::unwrap)
+                .collect();
+
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_paginate_stops_at_first_error() {
+        // `/page2` is not in `pages`, so fetching it (as `/page1` instructs) fails.
+        let pages = page_source(HashMap::from([("/page1", (vec![1], Some("/page2")))]));
+
+        let items: Vec<Result<u32, CBError>> =
+            futures::executor::block_on(paginate("/page1".to_string(), pages).collect::<Vec<_>>());
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(*items[0].as_ref().unwrap(), 1);
+        assert!(items[1].is_err());
+    }
+
+    #[test]
+    fn test_paginate_terminates_immediately_with_no_items_and_no_next_uri() {
+        let pages = page_source(HashMap::from([("/only", (vec![], None))]));
+
+        let items: Vec<u32> =
+            futures::executor::block_on(paginate("/only".to_string(), pages).collect::<Vec<_>>())
+                .into_iter()
+                .map(Result::unwrap)
+                .collect();
+
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn test_date_range_inclusive_single_day() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert_eq!(date_range_inclusive(date, date), vec![date]);
+    }
+
+    #[test]
+    fn test_date_range_inclusive_spans_multiple_days() {
+        let from = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let to = chrono::NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+        assert_eq!(
+            date_range_inclusive(from, to),
+            vec![
+                chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                chrono::NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+                chrono::NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_date_range_inclusive_is_empty_when_from_is_after_to() {
+        let from = chrono::NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+        let to = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert!(date_range_inclusive(from, to).is_empty());
+    }
+
+    /// A future that stays `Pending` for `remaining_polls` polls before resolving to `value`.
+    /// Used to make earlier stream items resolve *after* later ones, so a test relying on
+    /// `.buffered()`'s ordering guarantee can't pass by accident.
+    struct SlowToFast<T> {
+        value: Option<T>,
+        remaining_polls: u32,
+    }
+
+    impl<T: Unpin> std::future::Future for SlowToFast<T> {
+        type Output = T;
+
+        fn poll(
+            mut self: std::pin::Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Self::Output> {
+            if self.remaining_polls == 0 {
+                std::task::Poll::Ready(self.value.take().expect("polled after completion"))
+            } else {
+                self.remaining_polls -= 1;
+                cx.waker().wake_by_ref();
+                std::task::Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn test_buffered_preserves_submission_order_even_when_later_items_finish_first() {
+        let dates = date_range_inclusive(
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 5).unwrap(),
+        );
+
+        // Earlier dates take more polls to resolve than later ones, so the only way this comes
+        // back in date order is if `.buffered()` preserves submission order rather than
+        // yielding whichever future finishes first.
+        let results: Vec<chrono::NaiveDate> = futures::executor::block_on(
+            stream::iter(dates.clone().into_iter().enumerate())
+                .map(|(index, date)| SlowToFast {
+                    value: Some(date),
+                    remaining_polls: (dates.len() - index) as u32,
+                })
+                .buffered(dates.len())
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(results, dates);
+    }
+
+    #[test]
+    fn test_invert_price_computes_reciprocal() {
+        let amount = BigDecimal::from_str("2.5").unwrap();
+        let inverted = invert_price(amount, "USD", "EUR").unwrap();
+        assert_eq!(inverted, BigDecimal::from(1) / BigDecimal::from_str("2.5").unwrap());
+    }
+
+    #[test]
+    fn test_invert_price_rejects_zero_instead_of_dividing_by_it() {
+        let err = invert_price(BigDecimal::zero(), "USD", "EUR").unwrap_err();
+        assert!(matches!(err, CBError::NoPricePath { .. }));
+    }
+
+    #[test]
+    fn test_combine_bridge_legs_multiplies() {
+        let from_bridge = BigDecimal::from_str("2").unwrap();
+        let bridge_to = BigDecimal::from_str("3").unwrap();
+        assert_eq!(
+            combine_bridge_legs(from_bridge, bridge_to),
+            BigDecimal::from_str("6").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_approx_spot_price_via_uses_the_direct_pair_when_quoted() {
+        let quote = quote_from(HashMap::from([("BTC-USD", "50000")]));
+        let result =
+            futures::executor::block_on(approx_spot_price_via("BTC", "USD", quote)).unwrap();
+        assert_eq!(result.amount, BigDecimal::from_str("50000").unwrap());
+        assert_eq!(result.bridge, None);
+    }
+
+    #[test]
+    fn test_approx_spot_price_via_bridges_through_btc_when_no_direct_pair() {
+        // No LTC-EUR product, but both legs of the BTC bridge exist.
+        let quote = quote_from(HashMap::from([("LTC-BTC", "0.002"), ("BTC-EUR", "46000")]));
+        let result =
+            futures::executor::block_on(approx_spot_price_via("LTC", "EUR", quote)).unwrap();
+        assert_eq!(result.bridge.as_deref(), Some("BTC"));
+        assert_eq!(
+            result.amount,
+            BigDecimal::from_str("0.002").unwrap() * BigDecimal::from_str("46000").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_approx_spot_price_via_falls_back_to_usd_bridge_when_btc_bridge_unavailable() {
+        // Neither leg of the BTC bridge is quoted, but both legs of the USD bridge are.
+        let quote = quote_from(HashMap::from([("LTC-USD", "70"), ("USD-EUR", "0.9")]));
+        let result =
+            futures::executor::block_on(approx_spot_price_via("LTC", "EUR", quote)).unwrap();
+        assert_eq!(result.bridge.as_deref(), Some("USD"));
+        assert_eq!(
+            result.amount,
+            BigDecimal::from_str("70").unwrap() * BigDecimal::from_str("0.9").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_approx_spot_price_via_inverts_a_leg_when_only_the_reverse_pair_is_quoted() {
+        // Only the reverse of BTC-EUR (i.e. EUR-BTC) is quoted; leg_price_via should invert it.
+        let quote = quote_from(HashMap::from([("BTC-USD", "50000"), ("EUR-BTC", "0.00002")]));
+        let result =
+            futures::executor::block_on(approx_spot_price_via("USD", "EUR", quote)).unwrap();
+        assert_eq!(result.bridge.as_deref(), Some("BTC"));
+        let expected_usd_btc = BigDecimal::from(1) / BigDecimal::from_str("50000").unwrap();
+        let expected_btc_eur = BigDecimal::from(1) / BigDecimal::from_str("0.00002").unwrap();
+        assert_eq!(result.amount, expected_usd_btc * expected_btc_eur);
+    }
+
+    #[test]
+    fn test_approx_spot_price_via_reports_no_price_path_when_nothing_quotes() {
+        let quote = quote_from(HashMap::new());
+        let err = futures::executor::block_on(approx_spot_price_via("LTC", "EUR", quote))
+            .unwrap_err();
+        assert!(matches!(err, CBError::NoPricePath { .. }));
+    }
+
     #[test]
     fn test_currencies_deserialize() {
         let input = r#"